@@ -0,0 +1,208 @@
+use crate::conn::HandshakeStatus;
+use crate::error::Error;
+use crate::packet::{
+    MsgType::{Request, Response},
+    Packet,
+    RconReq::{self, Auth, ExecCommand},
+    RconResp::{AuthResponse, ResponseValue},
+    MAX_CMD_SIZE, MAX_PACKET_SIZE,
+};
+
+use tokio::net::{TcpStream, ToSocketAddrs};
+
+/// Async counterpart to [`Connection`](crate::conn::Connection), built on
+/// Tokio. Mirrors the blocking API, but every operation is `.await`-able.
+pub struct AsyncConnection {
+    stream: TcpStream,
+    next_id: i32,
+}
+
+impl AsyncConnection {
+    /// # Errors
+    /// Will return `Err` if a TCP connection cannot be established, or if authentication fails.
+    pub async fn connect(
+        addr: impl ToSocketAddrs,
+        password: impl AsRef<str>,
+    ) -> Result<AsyncConnection, Error> {
+        let stream = TcpStream::connect(addr).await?;
+        let mut conn = AsyncConnection { stream, next_id: 0 };
+
+        conn.auth(password.as_ref()).await?;
+
+        Ok(conn)
+    }
+
+    async fn auth(&mut self, password: &str) -> Result<HandshakeStatus, Error> {
+        let auth_id = self.send(Auth, password).await?;
+
+        let (response, status) = loop {
+            let p = Packet::deserialize_async(&mut self.stream, MAX_PACKET_SIZE).await?;
+            match p.ptype {
+                Response(ResponseValue) => {
+                    if p.body.is_empty() && p.id == auth_id {
+                        break (None, HandshakeStatus::Matched);
+                    }
+                }
+                Response(AuthResponse) => {
+                    break (Some(p), HandshakeStatus::BareAuthResponse);
+                }
+                _ => {}
+            }
+        };
+
+        let auth_response = match response {
+            Some(p) => p,
+            None => Packet::deserialize_async(&mut self.stream, MAX_PACKET_SIZE).await?,
+        };
+
+        if auth_response.ptype != Response(AuthResponse) || auth_response.is_error() {
+            return Err(Error::AuthFailure);
+        }
+
+        Ok(status)
+    }
+
+    /// # Errors
+    /// Will return `Err` if `cmd` is larger than [`MAX_CMD_SIZE`] bytes, or if the bytes cannot be
+    /// written to the TCP socket.
+    pub async fn exec(&mut self, cmd: &str) -> Result<String, Error> {
+        if cmd.len() > MAX_CMD_SIZE {
+            return Err(Error::CmdTooLong(cmd.len()));
+        }
+
+        self.send(ExecCommand, cmd).await?;
+        self.recv_multi_packet_response().await
+    }
+
+    async fn send(&mut self, request: RconReq, body: &str) -> Result<i32, Error> {
+        let id = self.fetch_and_add_id();
+        let packet = Packet::new(id, Request(request), body.into())?;
+        packet.serialize_async(&mut self.stream).await?;
+        Ok(id)
+    }
+
+    async fn recv_multi_packet_response(&mut self) -> Result<String, Error> {
+        let end_id = self.send(ExecCommand, "").await?; // empty packet
+        let mut response = String::new();
+        loop {
+            let recv_packet = Packet::deserialize_async(&mut self.stream, MAX_PACKET_SIZE).await?;
+            if recv_packet.id == end_id {
+                break;
+            }
+            response += &recv_packet.body;
+        }
+        Ok(response)
+    }
+
+    /// Increment the packet ID and return the current one.
+    /// Wraps back to `1` on overflow.
+    fn fetch_and_add_id(&mut self) -> i32 {
+        let id = self.next_id;
+        self.next_id = self.next_id.checked_add(1).unwrap_or(1);
+        id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use tokio::net::TcpListener;
+
+    async fn send_response_value(sock: &mut TcpStream, id: i32, body: &str) {
+        Packet::new(id, Response(ResponseValue), body.into())
+            .unwrap()
+            .serialize_async(sock)
+            .await
+            .unwrap();
+    }
+
+    async fn send_auth_response(sock: &mut TcpStream, id: i32) {
+        Packet::new(id, Response(AuthResponse), String::new())
+            .unwrap()
+            .serialize_async(sock)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn connect_matches_two_packet_handshake() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            let (mut sock, _) = listener.accept().await.unwrap();
+            let auth = Packet::deserialize_async(&mut sock, MAX_PACKET_SIZE)
+                .await
+                .unwrap();
+            send_response_value(&mut sock, auth.id, "").await;
+            send_auth_response(&mut sock, 0).await;
+        });
+
+        assert!(AsyncConnection::connect(addr, "passwrd").await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn connect_accepts_bare_auth_response() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            let (mut sock, _) = listener.accept().await.unwrap();
+            Packet::deserialize_async(&mut sock, MAX_PACKET_SIZE)
+                .await
+                .unwrap();
+            // Server skips the ResponseValue and answers with only AuthResponse.
+            send_auth_response(&mut sock, 0).await;
+        });
+
+        assert!(AsyncConnection::connect(addr, "passwrd").await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn connect_fails_on_auth_error_id() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            let (mut sock, _) = listener.accept().await.unwrap();
+            Packet::deserialize_async(&mut sock, MAX_PACKET_SIZE)
+                .await
+                .unwrap();
+            // A negative id indicates a failed authentication.
+            send_auth_response(&mut sock, -1).await;
+        });
+
+        let err = AsyncConnection::connect(addr, "wrong").await.unwrap_err();
+        assert!(matches!(err, Error::AuthFailure));
+    }
+
+    #[tokio::test]
+    async fn exec_assembles_multi_packet_response() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            let (mut sock, _) = listener.accept().await.unwrap();
+            let auth = Packet::deserialize_async(&mut sock, MAX_PACKET_SIZE)
+                .await
+                .unwrap();
+            send_response_value(&mut sock, auth.id, "").await;
+            send_auth_response(&mut sock, 0).await;
+
+            let req = Packet::deserialize_async(&mut sock, MAX_PACKET_SIZE)
+                .await
+                .unwrap();
+            let end = Packet::deserialize_async(&mut sock, MAX_PACKET_SIZE)
+                .await
+                .unwrap();
+            send_response_value(&mut sock, req.id, "a").await;
+            send_response_value(&mut sock, req.id, "b").await;
+            send_response_value(&mut sock, end.id, "").await;
+        });
+
+        let mut conn = AsyncConnection::connect(addr, "passwrd").await.unwrap();
+        let body = conn.exec("list").await.unwrap();
+        assert_eq!(body, "ab");
+    }
+}
@@ -0,0 +1,116 @@
+use bytes::BytesMut;
+use tokio_util::codec::{Decoder, Encoder};
+
+use crate::error::Error;
+use crate::packet::{Packet, MAX_PACKET_SIZE};
+
+/// Length-delimited codec for [`Packet`] framing, for use with
+/// `tokio_util::codec::Framed` or any other buffer-driven reactor.
+///
+/// [`decode`](Decoder::decode) peeks the leading little-endian `i32` size and
+/// leaves `src` untouched until a complete frame (`size + 4` bytes) is
+/// buffered, so it composes with partial reads from a non-blocking socket.
+pub struct PacketCodec {
+    max_packet_size: usize,
+}
+
+impl PacketCodec {
+    #[must_use]
+    pub fn new(max_packet_size: usize) -> Self {
+        Self { max_packet_size }
+    }
+}
+
+impl Default for PacketCodec {
+    fn default() -> Self {
+        Self::new(MAX_PACKET_SIZE)
+    }
+}
+
+impl Decoder for PacketCodec {
+    type Item = Packet;
+    type Error = Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Packet>, Error> {
+        if src.len() < 4 {
+            return Ok(None);
+        }
+
+        let size_raw = i32::from_le_bytes(src[..4].try_into().unwrap());
+        let size = Packet::validate_size(size_raw, self.max_packet_size)?;
+
+        let frame_len = 4 + size;
+        if src.len() < frame_len {
+            src.reserve(frame_len - src.len());
+            return Ok(None);
+        }
+
+        let frame = src.split_to(frame_len);
+        Packet::parse_frame(&frame).map(Some)
+    }
+}
+
+impl Encoder<Packet> for PacketCodec {
+    type Error = Error;
+
+    fn encode(&mut self, item: Packet, dst: &mut BytesMut) -> Result<(), Error> {
+        let buf = item.encode()?;
+        dst.reserve(buf.len());
+        dst.extend_from_slice(&buf);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::packet::{MsgType, RconReq};
+
+    #[test]
+    fn decode_waits_for_a_split_buffer() {
+        let packet = Packet::new(1, MsgType::Request(RconReq::ExecCommand), "list".into())
+            .expect("body should fit in packet");
+        let bytes = packet.encode().expect("packet should serialize");
+
+        let mut codec = PacketCodec::default();
+        let mut src = BytesMut::from(&bytes[..bytes.len() - 1]);
+        assert_eq!(codec.decode(&mut src).unwrap(), None);
+        assert_eq!(src.len(), bytes.len() - 1, "partial frame must stay buffered");
+
+        src.extend_from_slice(&bytes[bytes.len() - 1..]);
+        let decoded = codec.decode(&mut src).unwrap().expect("full frame should decode");
+        assert_eq!(decoded.id, 1);
+        assert_eq!(decoded.body, "list");
+        assert!(src.is_empty(), "consumed frame should be removed from src");
+    }
+
+    #[test]
+    fn decode_rejects_too_large_size() {
+        // size = 4097, id = 42, ptype = 0, body = "", \0\0
+        let data = [1, 16, 0, 0, 42, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        let mut src = BytesMut::from(&data[..]);
+
+        let mut codec = PacketCodec::default();
+        let res = codec.decode(&mut src);
+        assert!(res.is_err());
+        let Err(Error::InvalidPacketSize(s)) = res else {
+            panic!();
+        };
+        assert_eq!(s, 4097);
+    }
+
+    #[test]
+    fn encode_decode_round_trip() {
+        let packet = Packet::new(9, MsgType::Request(RconReq::Auth), "passwrd".into())
+            .expect("body should fit in packet");
+
+        let mut codec = PacketCodec::default();
+        let mut buf = BytesMut::new();
+        codec.encode(packet, &mut buf).unwrap();
+
+        let decoded = codec.decode(&mut buf).unwrap().expect("encoded frame should decode");
+        assert_eq!(decoded.id, 9);
+        assert_eq!(decoded.body, "passwrd");
+        assert!(buf.is_empty());
+    }
+}
@@ -2,11 +2,12 @@ use crate::error::Error;
 use crate::packet::{
     MsgType::{Request, Response},
     Packet,
-    ReqType::{self, AuthRequest, ExecCommand},
-    ResType::{AuthResponse, ResponseValue},
-    MAX_CMD_SIZE,
+    RconReq::{self, Auth, ExecCommand},
+    RconResp::{AuthResponse, ResponseValue},
+    MAX_CMD_SIZE, MAX_PACKET_SIZE,
 };
 
+use std::collections::HashMap;
 use std::net::{TcpStream, ToSocketAddrs};
 use std::time::Duration;
 
@@ -16,7 +17,7 @@ use std::time::Duration;
 /// request, and one [`AuthResponse`] to indicate the authentication result.
 /// Some servers may omit the [`ResponseValue`] and only send an
 /// [`AuthResponse`]. This could be considered unsafe.
-enum HandshakeStatus {
+pub(crate) enum HandshakeStatus {
     /// The handshake was matched to the specific authentication request.
     Matched,
     /// Only an [`AuthResponse`] was received from the server, not guaranteed
@@ -27,6 +28,11 @@ enum HandshakeStatus {
 pub struct Connection {
     stream: TcpStream,
     next_id: i32,
+    read_timeout: Duration,
+    response_timeout: Duration,
+    max_packet_size: usize,
+    max_cmd_size: usize,
+    strict_auth: bool,
 }
 
 impl Connection {
@@ -36,21 +42,11 @@ impl Connection {
         addr: impl ToSocketAddrs,
         password: impl AsRef<str>,
     ) -> Result<Connection, Error> {
-        // Create a TCP stream.
-        let stream = TcpStream::connect(addr)?;
-        stream.set_read_timeout(Some(Duration::from_secs(5)))?;
-
-        // Create a new RCON connection.
-        let mut conn = Connection { stream, next_id: 0 };
-
-        // Attempt to authenticate the connection.
-        conn.auth(password.as_ref())?;
-
-        Ok(conn)
+        ConnectionBuilder::default().connect(addr, password)
     }
 
     fn auth(&mut self, password: &str) -> Result<HandshakeStatus, Error> {
-        let auth_id = self.send(AuthRequest, password)?;
+        let auth_id = self.send(Auth, password)?;
 
         // The protocol says that the server response to an Auth packet is:
         //   1. An empty ResponseValue with ID matching the Auth packet, followed by
@@ -60,7 +56,7 @@ impl Connection {
         // but will also accept an AuthResponse upfront.
 
         let (response, status) = loop {
-            let p = Packet::deserialize(&mut self.stream)?;
+            let p = Packet::deserialize(&mut self.stream, self.max_packet_size)?;
             match p.ptype {
                 Response(ResponseValue) => {
                     // Received an empty ResponseValue, which should match the `auth_id`.
@@ -76,9 +72,15 @@ impl Connection {
             }
         };
 
+        if response.is_some() && self.strict_auth {
+            // A bare AuthResponse cannot be tied to our specific AuthRequest;
+            // the strict policy treats that ambiguity as a failed handshake.
+            return Err(Error::StrictAuthRejected);
+        }
+
         let auth_response = match response {
             Some(p) => p,
-            None => Packet::deserialize(&mut self.stream)?, // receive next packet as AuthResponse
+            None => Packet::deserialize(&mut self.stream, self.max_packet_size)?, // receive next packet as AuthResponse
         };
 
         // Check if authentication was successful.
@@ -90,12 +92,12 @@ impl Connection {
     }
 
     /// # Errors
-    /// Will return `Err` if `cmd` is larger than [`MAX_CMD_SIZE`] bytes, or if the bytes cannot be
-    /// written to the TCP socket.
+    /// Will return `Err` if `cmd` is larger than the configured max command size, or if the
+    /// bytes cannot be written to the TCP socket.
     pub fn exec(&mut self, cmd: &str) -> Result<String, Error> {
         // Note: The client-to-server max payload is sometimes limited; for
         // Minecraft this is 1446 bytes.
-        if cmd.len() > MAX_CMD_SIZE {
+        if cmd.len() > self.max_cmd_size {
             return Err(Error::CmdTooLong(cmd.len()));
         }
 
@@ -106,7 +108,62 @@ impl Connection {
         Ok(response)
     }
 
-    fn send(&mut self, request: ReqType, body: &str) -> Result<i32, Error> {
+    /// Execute several commands in a single round trip instead of paying a
+    /// round-trip latency per command.
+    ///
+    /// All requests are written up front, each followed by its own empty
+    /// sentinel packet so a multi-packet response can still be bounded. The
+    /// RCON protocol answers requests in the order they were received (FIFO),
+    /// so responses are demultiplexed back to their command by packet `id`.
+    /// Only an over-long command is reported per-command, as `Err` at its
+    /// index in the returned `Vec`; a send or read failure means the
+    /// connection itself is broken and aborts the whole batch.
+    ///
+    /// # Errors
+    /// Will return `Err` if a command cannot be sent, or if the connection is
+    /// lost while collecting responses.
+    pub fn exec_many(&mut self, cmds: &[&str]) -> Result<Vec<Result<String, Error>>, Error> {
+        let mut bodies: Vec<Result<String, Error>> = Vec::with_capacity(cmds.len());
+        let mut req_index: HashMap<i32, usize> = HashMap::new();
+        let mut end_index: HashMap<i32, usize> = HashMap::new();
+        let mut in_flight = 0usize;
+
+        for &cmd in cmds {
+            if cmd.len() > self.max_cmd_size {
+                bodies.push(Err(Error::CmdTooLong(cmd.len())));
+                continue;
+            }
+
+            // `fetch_and_add_id` hands out the next ID per call (wrapping to `1`
+            // on overflow), so the sentinel below is always distinct from the
+            // request it terminates.
+            let req_id = self.send(ExecCommand, cmd)?;
+            let end_id = self.send(ExecCommand, "")?;
+
+            req_index.insert(req_id, bodies.len());
+            end_index.insert(end_id, bodies.len());
+            bodies.push(Ok(String::new()));
+            in_flight += 1;
+        }
+
+        self.with_response_timeout(|conn| {
+            while in_flight > 0 {
+                let recv_packet = Packet::deserialize(&mut conn.stream, conn.max_packet_size)?;
+                if end_index.remove(&recv_packet.id).is_some() {
+                    in_flight -= 1;
+                } else if let Some(&idx) = req_index.get(&recv_packet.id) {
+                    if let Ok(body) = &mut bodies[idx] {
+                        body.push_str(&recv_packet.body);
+                    }
+                }
+            }
+            Ok(())
+        })?;
+
+        Ok(bodies)
+    }
+
+    fn send(&mut self, request: RconReq, body: &str) -> Result<i32, Error> {
         let id = self.fetch_and_add_id();
         let packet = Packet::new(id, Request(request), body.into())?;
         packet.serialize(&mut self.stream)?;
@@ -119,15 +176,33 @@ impl Connection {
         // can detect the end of a multi-packet response when receiving the response to the
         // empty packet.
         let end_id = self.send(ExecCommand, "")?; // empty packet
-        let mut response = String::new();
-        loop {
-            let recv_packet = Packet::deserialize(&mut self.stream)?;
-            if recv_packet.id == end_id {
-                break;
+
+        // A large dump can take longer than the connection's normal read
+        // timeout to arrive; relax it to the configured response deadline
+        // while collecting the (possibly multi-packet) response.
+        self.with_response_timeout(|conn| {
+            let mut response = String::new();
+            loop {
+                let recv_packet = Packet::deserialize(&mut conn.stream, conn.max_packet_size)?;
+                if recv_packet.id == end_id {
+                    break;
+                }
+                response += &recv_packet.body;
             }
-            response += &recv_packet.body;
-        }
-        Ok(response)
+            Ok(response)
+        })
+    }
+
+    /// Run `f` with the socket's read timeout relaxed to `response_timeout`,
+    /// restoring `read_timeout` once `f` returns, whether it succeeded or not.
+    fn with_response_timeout<T>(
+        &mut self,
+        f: impl FnOnce(&mut Self) -> Result<T, Error>,
+    ) -> Result<T, Error> {
+        self.stream.set_read_timeout(Some(self.response_timeout))?;
+        let result = f(self);
+        self.stream.set_read_timeout(Some(self.read_timeout))?;
+        result
     }
 
     /// Increment the packet ID and return the current one.
@@ -139,3 +214,191 @@ impl Connection {
         id
     }
 }
+
+/// Builder for a [`Connection`], overriding the values [`Connection::connect`]
+/// otherwise hard-codes: timeouts, frame-size limits, and auth strictness.
+pub struct ConnectionBuilder {
+    read_timeout: Duration,
+    response_timeout: Duration,
+    max_packet_size: usize,
+    max_cmd_size: usize,
+    strict_auth: bool,
+}
+
+impl Default for ConnectionBuilder {
+    fn default() -> Self {
+        Self {
+            read_timeout: Duration::from_secs(5),
+            response_timeout: Duration::from_secs(30),
+            max_packet_size: MAX_PACKET_SIZE,
+            max_cmd_size: MAX_CMD_SIZE,
+            strict_auth: false,
+        }
+    }
+}
+
+impl ConnectionBuilder {
+    /// Read timeout applied to the connection's socket outside of a
+    /// multi-packet response. Defaults to 5 seconds.
+    #[must_use]
+    pub fn read_timeout(mut self, timeout: Duration) -> Self {
+        self.read_timeout = timeout;
+        self
+    }
+
+    /// Read timeout applied while collecting a (possibly multi-packet)
+    /// command response. Defaults to 30 seconds, which accommodates large
+    /// dumps.
+    #[must_use]
+    pub fn response_timeout(mut self, timeout: Duration) -> Self {
+        self.response_timeout = timeout;
+        self
+    }
+
+    /// Largest server-to-client packet this connection will accept. Some
+    /// non-Source servers deviate from the default 4096-byte limit.
+    #[must_use]
+    pub fn max_packet_size(mut self, size: usize) -> Self {
+        self.max_packet_size = size;
+        self
+    }
+
+    /// Largest client-to-server command this connection will send.
+    #[must_use]
+    pub fn max_cmd_size(mut self, size: usize) -> Self {
+        self.max_cmd_size = size;
+        self
+    }
+
+    /// Reject the [`HandshakeStatus::BareAuthResponse`] case as an
+    /// [`Error::StrictAuthRejected`] instead of accepting it, for
+    /// security-sensitive deployments.
+    #[must_use]
+    pub fn strict_auth(mut self, strict: bool) -> Self {
+        self.strict_auth = strict;
+        self
+    }
+
+    /// # Errors
+    /// Will return `Err` if a TCP connection cannot be established, or if authentication fails.
+    pub fn connect(
+        self,
+        addr: impl ToSocketAddrs,
+        password: impl AsRef<str>,
+    ) -> Result<Connection, Error> {
+        // Create a TCP stream.
+        let stream = TcpStream::connect(addr)?;
+        stream.set_read_timeout(Some(self.read_timeout))?;
+
+        // Create a new RCON connection.
+        let mut conn = Connection {
+            stream,
+            next_id: 0,
+            read_timeout: self.read_timeout,
+            response_timeout: self.response_timeout,
+            max_packet_size: self.max_packet_size,
+            max_cmd_size: self.max_cmd_size,
+            strict_auth: self.strict_auth,
+        };
+
+        // Attempt to authenticate the connection.
+        conn.auth(password.as_ref())?;
+
+        Ok(conn)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::net::TcpListener;
+    use std::thread;
+
+    /// Build a `Connection` wrapping an already-connected stream, skipping
+    /// the auth handshake so tests can drive `exec`/`exec_many` directly
+    /// against a fake server.
+    fn unauthed_connection(stream: TcpStream) -> Connection {
+        Connection {
+            stream,
+            next_id: 0,
+            read_timeout: Duration::from_secs(5),
+            response_timeout: Duration::from_secs(5),
+            max_packet_size: MAX_PACKET_SIZE,
+            max_cmd_size: MAX_CMD_SIZE,
+            strict_auth: false,
+        }
+    }
+
+    #[test]
+    fn exec_many_demuxes_interleaved_multi_packet_responses() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let server = thread::spawn(move || {
+            let (mut sock, _) = listener.accept().unwrap();
+
+            // Client sends: "list" (id 0), sentinel (id 1), "time" (id 2),
+            // sentinel (id 3), in that order.
+            let req_list = Packet::deserialize(&mut sock, MAX_PACKET_SIZE).unwrap();
+            let end_list = Packet::deserialize(&mut sock, MAX_PACKET_SIZE).unwrap();
+            let req_time = Packet::deserialize(&mut sock, MAX_PACKET_SIZE).unwrap();
+            let end_time = Packet::deserialize(&mut sock, MAX_PACKET_SIZE).unwrap();
+
+            // Answer "list" with a multi-packet response before answering
+            // "time", to prove responses are demultiplexed by id rather than
+            // assumed to arrive in a fixed per-command order.
+            let send = |sock: &mut TcpStream, id: i32, body: &str| {
+                Packet::new(id, Response(ResponseValue), body.into())
+                    .unwrap()
+                    .serialize(sock)
+                    .unwrap();
+            };
+            send(&mut sock, req_list.id, "a");
+            send(&mut sock, req_list.id, "b");
+            send(&mut sock, req_time.id, "t");
+            send(&mut sock, end_time.id, "");
+            send(&mut sock, end_list.id, "");
+        });
+
+        let client = TcpStream::connect(addr).unwrap();
+        let mut conn = unauthed_connection(client);
+
+        let bodies = conn.exec_many(&["list", "time"]).unwrap();
+        server.join().unwrap();
+
+        assert_eq!(bodies[0].as_ref().unwrap(), "ab");
+        assert_eq!(bodies[1].as_ref().unwrap(), "t");
+    }
+
+    #[test]
+    fn exec_many_reports_over_long_command_per_index() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let server = thread::spawn(move || {
+            let (mut sock, _) = listener.accept().unwrap();
+            let req = Packet::deserialize(&mut sock, MAX_PACKET_SIZE).unwrap();
+            let end = Packet::deserialize(&mut sock, MAX_PACKET_SIZE).unwrap();
+            Packet::new(req.id, Response(ResponseValue), "ok".into())
+                .unwrap()
+                .serialize(&mut sock)
+                .unwrap();
+            Packet::new(end.id, Response(ResponseValue), String::new())
+                .unwrap()
+                .serialize(&mut sock)
+                .unwrap();
+        });
+
+        let client = TcpStream::connect(addr).unwrap();
+        let mut conn = unauthed_connection(client);
+
+        let too_long = "a".repeat(MAX_CMD_SIZE + 1);
+        let bodies = conn.exec_many(&[too_long.as_str(), "ok"]).unwrap();
+        server.join().unwrap();
+
+        assert!(bodies[0].is_err());
+        assert_eq!(bodies[1].as_ref().unwrap(), "ok");
+    }
+}
+
@@ -26,4 +26,7 @@ pub enum Error {
 
     #[error("authentication failed")]
     AuthFailure,
+
+    #[error("bare auth response rejected by strict auth policy")]
+    StrictAuthRejected,
 }
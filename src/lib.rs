@@ -0,0 +1,18 @@
+pub mod conn;
+pub mod error;
+pub mod nonblocking;
+pub mod packet;
+
+#[cfg(feature = "tokio")]
+pub mod async_conn;
+#[cfg(feature = "tokio")]
+pub mod codec;
+
+pub use conn::{Connection, ConnectionBuilder};
+pub use error::Error;
+pub use nonblocking::{NonBlockingConnection, WriteStatus};
+
+#[cfg(feature = "tokio")]
+pub use async_conn::AsyncConnection;
+#[cfg(feature = "tokio")]
+pub use codec::PacketCodec;
@@ -0,0 +1,235 @@
+use std::collections::VecDeque;
+use std::io::{Cursor, Read, Write};
+use std::net::TcpStream;
+
+use crate::error::Error;
+use crate::packet::{
+    MsgType,
+    Packet,
+    RconReq,
+    MAX_PACKET_SIZE,
+};
+
+/// The number of bytes making up a packet's leading length prefix.
+const LEN_PREFIX_SIZE: usize = 4;
+
+/// Result of draining [`NonBlockingConnection`]'s send queue for one
+/// `writable()` call.
+pub enum WriteStatus {
+    /// Bytes remain queued; call `writable()` again once the socket is
+    /// writable.
+    Ongoing,
+    /// The send queue has been fully drained.
+    Complete,
+}
+
+/// A non-blocking RCON connection driven from an external event loop (e.g.
+/// mio/epoll), rather than through blocking reads and writes.
+///
+/// Outbound packets are enqueued and drained by [`writable`](Self::writable);
+/// inbound bytes are accumulated by [`readable`](Self::readable) until a full
+/// packet is buffered.
+pub struct NonBlockingConnection {
+    stream: TcpStream,
+    next_id: i32,
+    send_queue: VecDeque<Cursor<Vec<u8>>>,
+    read_buf: Vec<u8>,
+    expected: usize,
+}
+
+impl NonBlockingConnection {
+    /// Wrap `stream`, switching it into non-blocking mode.
+    ///
+    /// # Errors
+    /// Will return `Err` if the socket cannot be switched into non-blocking mode.
+    pub fn new(stream: TcpStream) -> Result<Self, Error> {
+        stream.set_nonblocking(true)?;
+        Ok(Self {
+            stream,
+            next_id: 0,
+            send_queue: VecDeque::new(),
+            read_buf: Vec::new(),
+            expected: LEN_PREFIX_SIZE,
+        })
+    }
+
+    /// Enqueue a request packet for sending; the bytes are drained by
+    /// [`writable`](Self::writable).
+    ///
+    /// # Errors
+    /// Will return `Err` if `body` does not fit in an RCON packet.
+    pub fn submit(&mut self, request: RconReq, body: &str) -> Result<i32, Error> {
+        let id = self.fetch_and_add_id();
+        let packet = Packet::new(id, MsgType::Request(request), body.into())?;
+        self.send_queue.push_back(Cursor::new(packet.encode()?));
+        Ok(id)
+    }
+
+    /// Tell the read path how many bytes to accumulate before
+    /// [`readable`](Self::readable) yields a [`Packet`]. Used internally to
+    /// step from the 4-byte length prefix to the full frame size.
+    pub fn expect(&mut self, size: usize) {
+        self.expected = size;
+    }
+
+    /// Drain the outbound queue into the socket, returning
+    /// [`WriteStatus::Ongoing`] while bytes remain queued, or
+    /// [`WriteStatus::Complete`] once the queue is empty.
+    ///
+    /// # Errors
+    /// Will return `Err` if the underlying socket write fails.
+    pub fn writable(&mut self) -> Result<WriteStatus, Error> {
+        while let Some(cur) = self.send_queue.front_mut() {
+            let remaining = &cur.get_ref()[cur.position() as usize..];
+            if remaining.is_empty() {
+                self.send_queue.pop_front();
+                continue;
+            }
+
+            match self.stream.write(remaining) {
+                Ok(n) => {
+                    let pos = cur.position() + n as u64;
+                    cur.set_position(pos);
+                }
+                Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                    return Ok(WriteStatus::Ongoing);
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+        Ok(WriteStatus::Complete)
+    }
+
+    /// Accumulate bytes until a full packet is buffered, then return it.
+    /// Returns `Ok(None)` when the socket would block before a full packet
+    /// is available.
+    ///
+    /// # Errors
+    /// Will return `Err` if the socket read fails, the peer closes the
+    /// connection mid-frame, or a buffered frame is malformed.
+    pub fn readable(&mut self) -> Result<Option<Packet>, Error> {
+        loop {
+            if self.read_buf.len() < self.expected {
+                let mut chunk = [0u8; MAX_PACKET_SIZE];
+                match self.stream.read(&mut chunk) {
+                    Ok(0) => {
+                        return Err(Error::IO(std::io::Error::new(
+                            std::io::ErrorKind::UnexpectedEof,
+                            "connection closed mid-frame",
+                        )));
+                    }
+                    Ok(n) => self.read_buf.extend_from_slice(&chunk[..n]),
+                    Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => return Ok(None),
+                    Err(e) => return Err(e.into()),
+                }
+
+                if self.read_buf.len() < self.expected {
+                    continue;
+                }
+            }
+
+            // We now have at least `self.expected` bytes buffered.
+            if self.expected == LEN_PREFIX_SIZE {
+                // We have the length prefix buffered; learn the full frame
+                // size and keep accumulating.
+                let mut len_buf = [0u8; LEN_PREFIX_SIZE];
+                len_buf.copy_from_slice(&self.read_buf[..LEN_PREFIX_SIZE]);
+                let size = Packet::validate_size(i32::from_le_bytes(len_buf), MAX_PACKET_SIZE)?;
+                self.expect(LEN_PREFIX_SIZE + size);
+                continue;
+            }
+
+            // A full frame is buffered; split it off and reset for the next one.
+            let frame: Vec<u8> = self.read_buf.drain(..self.expected).collect();
+            self.expect(LEN_PREFIX_SIZE);
+            return Packet::parse_frame(&frame).map(Some);
+        }
+    }
+
+    /// Increment the packet ID and return the current one.
+    /// Wraps back to `1` on overflow.
+    fn fetch_and_add_id(&mut self) -> i32 {
+        let id = self.next_id;
+        self.next_id = self.next_id.checked_add(1).unwrap_or(1);
+        id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::net::TcpListener;
+    use std::thread::sleep;
+    use std::time::Duration;
+
+    /// Set up a connected loopback pair, returning `(client, server)`.
+    fn loopback_pair() -> (TcpStream, TcpStream) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let client = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+        let (server, _) = listener.accept().unwrap();
+        client.set_nodelay(true).unwrap();
+        server.set_nodelay(true).unwrap();
+        (client, server)
+    }
+
+    #[test]
+    fn readable_none_before_any_bytes() {
+        let (_client, server) = loopback_pair();
+        let mut conn = NonBlockingConnection::new(server).unwrap();
+        assert!(conn.readable().unwrap().is_none());
+    }
+
+    #[test]
+    fn readable_yields_packet_delivered_in_one_write() {
+        let (mut client, server) = loopback_pair();
+        let mut conn = NonBlockingConnection::new(server).unwrap();
+
+        let packet = Packet::new(7, MsgType::Request(RconReq::ExecCommand), "list".into()).unwrap();
+        client.write_all(&packet.encode().unwrap()).unwrap();
+        sleep(Duration::from_millis(20));
+
+        // A whole frame arriving in a single `read` must be yielded right
+        // away, not on some later call once no further readiness event fires.
+        let received = conn.readable().unwrap().expect("full frame should be ready");
+        assert_eq!(received.id, 7);
+        assert_eq!(received.body, "list");
+    }
+
+    #[test]
+    fn readable_accumulates_across_partial_writes() {
+        let (mut client, server) = loopback_pair();
+        let mut conn = NonBlockingConnection::new(server).unwrap();
+
+        let packet = Packet::new(3, MsgType::Request(RconReq::Auth), "passwrd".into()).unwrap();
+        let bytes = packet.encode().unwrap();
+
+        // Deliver only the length prefix first; the frame size isn't known
+        // to be complete yet, so no packet should be ready.
+        client.write_all(&bytes[..4]).unwrap();
+        sleep(Duration::from_millis(20));
+        assert!(conn.readable().unwrap().is_none());
+
+        // Deliver the rest of the frame.
+        client.write_all(&bytes[4..]).unwrap();
+        sleep(Duration::from_millis(20));
+        let received = conn
+            .readable()
+            .unwrap()
+            .expect("full frame should be ready after the remaining bytes arrive");
+        assert_eq!(received.id, 3);
+        assert_eq!(received.body, "passwrd");
+    }
+
+    #[test]
+    fn writable_drains_queued_packet() {
+        let (mut client, server) = loopback_pair();
+        let mut conn = NonBlockingConnection::new(server).unwrap();
+
+        conn.submit(RconReq::ExecCommand, "list").unwrap();
+        assert!(matches!(conn.writable().unwrap(), WriteStatus::Complete));
+
+        let received = Packet::deserialize(&mut client, MAX_PACKET_SIZE).unwrap();
+        assert_eq!(received.body, "list");
+    }
+}
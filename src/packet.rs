@@ -1,4 +1,3 @@
-use std::cmp::Ordering;
 use std::io::{Read, Write};
 
 use crate::error::Error;
@@ -103,7 +102,9 @@ impl Packet {
         })
     }
 
-    pub fn serialize(&self, w: &mut impl Write) -> Result<(), Error> {
+    /// Build the raw wire bytes for this packet, shared by the blocking and
+    /// async `serialize` variants.
+    pub(crate) fn encode(&self) -> Result<Vec<u8>, Error> {
         // Ensure size is within spec.
         if !(MIN_PACKET_SIZE..=MAX_PACKET_SIZE).contains(&self.size) {
             return Err(Error::InvalidPacketSize(self.size));
@@ -120,11 +121,73 @@ impl Packet {
         buf.extend_from_slice(&ptype_raw.to_le_bytes());
         buf.extend_from_slice(self.body.as_bytes());
         buf.extend_from_slice(&[0x00, 0x00]); // empty string and null terminator
-        w.write_all(&buf)?;
+        Ok(buf)
+    }
+
+    pub fn serialize(&self, w: &mut impl Write) -> Result<(), Error> {
+        w.write_all(&self.encode()?)?;
+        Ok(())
+    }
+
+    /// Async counterpart to [`serialize`](Self::serialize), built on the same
+    /// [`encode`](Self::encode) framing.
+    #[cfg(feature = "tokio")]
+    pub async fn serialize_async(
+        &self,
+        w: &mut (impl tokio::io::AsyncWrite + Unpin),
+    ) -> Result<(), Error> {
+        use tokio::io::AsyncWriteExt;
+        w.write_all(&self.encode()?).await?;
         Ok(())
     }
 
-    pub fn deserialize(r: &mut impl Read) -> Result<Self, Error> {
+    /// Validate a wire-format size field against the server-to-client limit
+    /// `max_size`, returning the packet body+header size on success.
+    pub(crate) fn validate_size(size_raw: i32, max_size: usize) -> Result<usize, Error> {
+        // Ensure size is valid: non-negative and within spec.
+        let Ok(size) = usize::try_from(size_raw) else {
+            return Err(Error::BadResponsePacket);
+        };
+        if !(MIN_PACKET_SIZE..=max_size).contains(&size) {
+            return Err(Error::InvalidPacketSize(size));
+        }
+        Ok(size)
+    }
+
+    /// Assemble a deserialized packet from its already-read parts, shared by
+    /// the blocking and async `deserialize` variants.
+    ///
+    /// Note: Deserialized packets will always be response messages. The tag
+    /// `2` is shared between `ExecCommand` (req) and `AuthResponse` (resp),
+    /// and only the latter is relevant here.
+    fn from_raw(id: i32, ptype_raw: i32, size: usize, body: Vec<u8>, term: [u8; 2]) -> Result<Self, Error> {
+        if term[0] != 0 || term[1] != 0 {
+            return Err(Error::BadResponsePacket);
+        }
+        Ok(Self {
+            size,
+            id,
+            ptype: MsgType::Response(RconResp::from(ptype_raw)),
+            body: String::from_utf8(body)?,
+        })
+    }
+
+    /// Parse a complete wire-format frame (length prefix included) that has
+    /// already been fully buffered, e.g. by [`NonBlockingConnection`] or
+    /// [`PacketCodec`].
+    ///
+    /// [`NonBlockingConnection`]: crate::nonblocking::NonBlockingConnection
+    /// [`PacketCodec`]: crate::codec::PacketCodec
+    pub(crate) fn parse_frame(frame: &[u8]) -> Result<Self, Error> {
+        let id = i32::from_le_bytes(frame[4..8].try_into().unwrap());
+        let ptype_raw = i32::from_le_bytes(frame[8..12].try_into().unwrap());
+        let size = frame.len() - 4; // minus the leading length prefix
+        let term = [frame[frame.len() - 2], frame[frame.len() - 1]];
+        let body = frame[12..frame.len() - 2].to_vec();
+        Self::from_raw(id, ptype_raw, size, body, term)
+    }
+
+    pub fn deserialize(r: &mut impl Read, max_size: usize) -> Result<Self, Error> {
         // Read i32 packet fields.
         let mut field_buf = [0u8; 4]; // tmp buffer for i32 packet fields
         r.read_exact(&mut field_buf)?;
@@ -134,42 +197,51 @@ impl Packet {
         r.read_exact(&mut field_buf)?;
         let ptype_raw = i32::from_le_bytes(field_buf);
 
-        // Ensure size is valid: non-negative and within spec.
-        let Ok(size) = usize::try_from(size_raw) else {
-            return Err(Error::BadResponsePacket);
-        };
-        if !(MIN_PACKET_SIZE..=MAX_PACKET_SIZE).contains(&size) {
-            return Err(Error::InvalidPacketSize(size));
-        }
+        let size = Self::validate_size(size_raw, max_size)?;
 
         // Read body.
         let body_len = size - MIN_PACKET_SIZE;
-        let body = match body_len.cmp(&0) {
-            Ordering::Greater => {
-                let mut body_buf = vec![0u8; body_len];
-                r.read_exact(&mut body_buf)?;
-                String::from_utf8(body_buf)?
-            }
-            Ordering::Equal => String::new(),
-            Ordering::Less => return Err(Error::BadResponsePacket),
-        };
+        let mut body_buf = vec![0u8; body_len];
+        if body_len > 0 {
+            r.read_exact(&mut body_buf)?;
+        }
 
         // Read terminating bytes.
         let mut term_buf = [0u8; 2];
         r.read_exact(&mut term_buf)?;
-        if term_buf[0] != 0 || term_buf[1] != 0 {
-            return Err(Error::BadResponsePacket);
+
+        Self::from_raw(id, ptype_raw, size, body_buf, term_buf)
+    }
+
+    /// Async counterpart to [`deserialize`](Self::deserialize), mirroring the
+    /// same framing: length prefix, id, type, body, then the `\0\0` terminator.
+    #[cfg(feature = "tokio")]
+    pub async fn deserialize_async(
+        r: &mut (impl tokio::io::AsyncRead + Unpin),
+        max_size: usize,
+    ) -> Result<Self, Error> {
+        use tokio::io::AsyncReadExt;
+
+        let mut field_buf = [0u8; 4];
+        r.read_exact(&mut field_buf).await?;
+        let size_raw = i32::from_le_bytes(field_buf);
+        r.read_exact(&mut field_buf).await?;
+        let id = i32::from_le_bytes(field_buf);
+        r.read_exact(&mut field_buf).await?;
+        let ptype_raw = i32::from_le_bytes(field_buf);
+
+        let size = Self::validate_size(size_raw, max_size)?;
+
+        let body_len = size - MIN_PACKET_SIZE;
+        let mut body_buf = vec![0u8; body_len];
+        if body_len > 0 {
+            r.read_exact(&mut body_buf).await?;
         }
 
-        // Note: Deserialized packets will always be response messages. The tag
-        // `2` is shared between `ExecCommand` (req) and `AuthResponse (resp),
-        // and only the latter is relevant here.
-        Ok(Self {
-            size,
-            id,
-            ptype: MsgType::Response(RconResp::from(ptype_raw)),
-            body,
-        })
+        let mut term_buf = [0u8; 2];
+        r.read_exact(&mut term_buf).await?;
+
+        Self::from_raw(id, ptype_raw, size, body_buf, term_buf)
     }
 
     pub fn is_error(&self) -> bool {
@@ -228,7 +300,7 @@ mod tests {
         let data = [10, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0];
 
         // Use a `Cursor` to fulfill the `Read` trait boundary on an array.
-        let packet = Packet::deserialize(&mut Cursor::new(data)).unwrap();
+        let packet = Packet::deserialize(&mut Cursor::new(data), MAX_PACKET_SIZE).unwrap();
 
         let mut buf = Vec::new();
         expected.serialize(&mut buf).unwrap();
@@ -242,7 +314,7 @@ mod tests {
         // A packet has a positive size.
         // size = -1, id = 42, ptype = 0, body = "", \0\0
         let data = [255, 255, 255, 255, 42, 0, 0, 0, 0, 0, 0, 0, 0, 0];
-        let res = Packet::deserialize(&mut Cursor::new(data));
+        let res = Packet::deserialize(&mut Cursor::new(data), MAX_PACKET_SIZE);
         assert!(res.is_err());
     }
 
@@ -251,7 +323,7 @@ mod tests {
         // A packet is at least 10 bytes.
         // size = 9, id = 42, ptype = 0, body = "", \0\0
         let data = [9, 0, 0, 0, 42, 0, 0, 0, 0, 0, 0, 0, 0, 0];
-        let res = Packet::deserialize(&mut Cursor::new(data));
+        let res = Packet::deserialize(&mut Cursor::new(data), MAX_PACKET_SIZE);
         assert!(res.is_err());
         let Err(Error::InvalidPacketSize(s)) = res else {
             panic!();
@@ -264,7 +336,7 @@ mod tests {
         // A packet is at most 4096 bytes.
         // size = 4097, id = 42, ptype = 0, body = "", \0\0
         let data = [1, 16, 0, 0, 42, 0, 0, 0, 0, 0, 0, 0, 0, 0];
-        let res = Packet::deserialize(&mut Cursor::new(data));
+        let res = Packet::deserialize(&mut Cursor::new(data), MAX_PACKET_SIZE);
         assert!(res.is_err());
         let Err(Error::InvalidPacketSize(s)) = res else {
             panic!();